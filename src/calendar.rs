@@ -0,0 +1,147 @@
+use std::fmt;
+
+/// Which calendar system a date's `year`/`month`/`day` are expressed in.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Calendar {
+    /// The calendar in common civil use today, extended backwards
+    /// indefinitely (proleptic Gregorian).
+    #[default]
+    Gregorian,
+    /// The Julian calendar as historically used (introduced 45 BCE).
+    /// Conversions apply the same every-fourth-year leap rule as
+    /// [`Calendar::ProlepticJulian`] at every year, including before 45 BCE.
+    Julian,
+    /// The Julian calendar's leap-year rule, extended backwards
+    /// indefinitely past its historical introduction.
+    ProlepticJulian,
+}
+
+impl Calendar {
+    /// Whether `year` is a leap year in this calendar.
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        match self {
+            Calendar::Gregorian => year % 4 == 0 && (year % 100 != 0 || year % 400 == 0),
+            Calendar::Julian | Calendar::ProlepticJulian => year % 4 == 0,
+        }
+    }
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Calendar::Gregorian => write!(f, "Gregorian"),
+            Calendar::Julian => write!(f, "Julian"),
+            Calendar::ProlepticJulian => write!(f, "proleptic Julian"),
+        }
+    }
+}
+
+/// Days accumulated by full years `0..year`, counting from a calendar's
+/// shifted new year (March 1), via its leap rule. This alone does not make
+/// day counts from different calendars comparable; see [`epoch_offset`].
+fn full_days_before_shifted_year(calendar: &Calendar, year: i64) -> i64 {
+    match calendar {
+        Calendar::Gregorian => {
+            365 * year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+        }
+        Calendar::Julian | Calendar::ProlepticJulian => 365 * year + year.div_euclid(4),
+    }
+}
+
+fn average_days_per_year(calendar: &Calendar) -> f64 {
+    match calendar {
+        Calendar::Gregorian => 365.2425,
+        Calendar::Julian | Calendar::ProlepticJulian => 365.25,
+    }
+}
+
+/// The two calendars' shifted-year-0 epochs don't fall on the same physical
+/// day: proleptic Gregorian 0001-01-01 is Julian 0001-01-03. This constant
+/// corrects for that fixed 2-day gap so day counts from either calendar are
+/// directly comparable (and e.g. Julian 1582-10-04 converts to Gregorian
+/// 1582-10-14, so the following day is Gregorian 1582-10-15, matching the
+/// real 1582 reform).
+fn epoch_offset(calendar: &Calendar) -> i64 {
+    match calendar {
+        Calendar::Gregorian => 0,
+        Calendar::Julian | Calendar::ProlepticJulian => -2,
+    }
+}
+
+/// Converts a calendar date to a continuous day count, via the month-shift
+/// trick of treating March as the first month of the year so that leap days
+/// fall at year end.
+pub(crate) fn to_day_count(calendar: &Calendar, year: i32, month: u8, day: u8) -> i64 {
+    let (shifted_year, m) = if month > 2 {
+        (year as i64, month as i64 - 3)
+    } else {
+        (year as i64 - 1, month as i64 + 9)
+    };
+    let day_of_year = (153 * m + 2) / 5 + (day as i64 - 1);
+    full_days_before_shifted_year(calendar, shifted_year) + day_of_year + epoch_offset(calendar)
+}
+
+/// Inverts [`to_day_count`], recovering the `(year, month, day)` a
+/// continuous day count corresponds to under `calendar`.
+pub(crate) fn from_day_count(calendar: &Calendar, days: i64) -> (i32, u8, u8) {
+    let days = days - epoch_offset(calendar);
+    let avg = average_days_per_year(calendar);
+    let mut shifted_year = (days as f64 / avg).floor() as i64;
+    while full_days_before_shifted_year(calendar, shifted_year) > days {
+        shifted_year -= 1;
+    }
+    while full_days_before_shifted_year(calendar, shifted_year + 1) <= days {
+        shifted_year += 1;
+    }
+
+    let day_of_year = days - full_days_before_shifted_year(calendar, shifted_year);
+    let m = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * m + 2) / 5 + 1) as u8;
+    let (year, month) = if m < 10 {
+        (shifted_year, m + 3)
+    } else {
+        (shifted_year + 1, m - 9)
+    };
+    (year as i32, month as u8, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_leap_year() {
+        assert!(Calendar::Gregorian.is_leap_year(2000));
+        assert!(!Calendar::Gregorian.is_leap_year(1900));
+        assert!(Calendar::Gregorian.is_leap_year(2024));
+        assert!(!Calendar::Gregorian.is_leap_year(2023));
+
+        assert!(Calendar::Julian.is_leap_year(1900));
+        assert!(Calendar::Julian.is_leap_year(2000));
+        assert!(!Calendar::Julian.is_leap_year(2023));
+    }
+
+    #[test]
+    fn test_day_count_roundtrip() {
+        for (calendar, year, month, day) in [
+            (Calendar::Gregorian, 2024, 10, 2),
+            (Calendar::Gregorian, 1, 1, 1),
+            (Calendar::Gregorian, -44, 3, 15),
+            (Calendar::Gregorian, 0, 12, 31),
+            (Calendar::Julian, 1582, 10, 4),
+            (Calendar::ProlepticJulian, -910, 9, 17),
+        ] {
+            let days = to_day_count(&calendar, year, month, day);
+            assert_eq!(from_day_count(&calendar, days), (year, month, day));
+        }
+    }
+
+    #[test]
+    fn test_gregorian_julian_1582_transition() {
+        // The day after 4 October 1582 (Julian) was 15 October 1582
+        // (Gregorian) when the reform took effect.
+        let julian_days = to_day_count(&Calendar::Julian, 1582, 10, 4);
+        let (year, month, day) = from_day_count(&Calendar::Gregorian, julian_days + 1);
+        assert_eq!((year, month, day), (1582, 10, 15));
+    }
+}
@@ -1,14 +1,140 @@
+use crate::calendar::{self, Calendar};
+use crate::language::EraStyle;
 use crate::{Language, Precision};
 use std::fmt;
+use std::str::FromStr;
 use time::Date;
 
+/// An era suffix recognized while parsing, paired with the sign and
+/// [`EraStyle`] it implies. Kept in descending length order per language so
+/// that e.g. `"BCE"` is tried before the `"CE"` it ends with.
+type EraSuffix = (&'static str, bool, EraStyle);
+
+fn era_suffixes(language: &Language) -> &'static [EraSuffix] {
+    match language {
+        // "BCE" is ambiguous between `Implicit` and `CeBce` (both render it
+        // identically); prefer `Implicit` since it's this crate's default.
+        Language::English => &[
+            ("BCE", true, EraStyle::Implicit),
+            ("BC", true, EraStyle::AdBc),
+            ("CE", false, EraStyle::CeBce),
+            ("AD", false, EraStyle::AdBc),
+        ],
+        Language::German => &[
+            ("v.Chr.", true, EraStyle::Implicit),
+            ("v.u.Z.", true, EraStyle::CeBce),
+            ("n.Chr.", false, EraStyle::AdBc),
+            ("u.Z.", false, EraStyle::CeBce),
+        ],
+    }
+}
+
+/// Error returned by [`Era::parse`] when the input does not match any of the
+/// shapes produced by [`Era`]'s `Display` implementation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseEraError {
+    Empty,
+    UnrecognizedFormat(String),
+}
+
+impl fmt::Display for ParseEraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseEraError::Empty => write!(f, "cannot parse an era from an empty string"),
+            ParseEraError::UnrecognizedFormat(s) => {
+                write!(f, "unrecognized era format: {s:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseEraError {}
+
+/// The result of [`Era::format`]: pre-rendered text from a pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormattedEra(String);
+
+impl fmt::Display for FormattedEra {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Error returned by [`Era::format`] for a malformed pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatEraError {
+    /// A `%` was followed by a character that is not a known token.
+    UnknownToken(char),
+    /// The pattern ended with a trailing, unterminated `%`.
+    TrailingPercent,
+}
+
+impl fmt::Display for FormatEraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatEraError::UnknownToken(c) => write!(f, "unknown format token: %{c}"),
+            FormatEraError::TrailingPercent => write!(f, "pattern ends with a trailing '%'"),
+        }
+    }
+}
+
+impl std::error::Error for FormatEraError {}
+
+/// Whether the stored `year` follows astronomical or historical numbering.
+///
+/// Astronomical numbering has a year 0 (1 BCE is year 0, 2 BCE is year -1,
+/// ...). Historical numbering has no year 0 (1 BCE is year -1, 2 BCE is year
+/// -2, ...). Dates coming from ISO 8601 or `time::Date` are astronomical;
+/// dates transcribed from historical sources are usually historical.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Numbering {
+    #[default]
+    Astronomical,
+    Historical,
+}
+
+/// The rounding unit and the unit a coarse bucket's count is expressed in,
+/// e.g. [`Precision::TenMillionYears`] rounds to the nearest ten million
+/// years but reports its count in millions ("10 million years").
+fn coarse_bucket(precision: &Precision) -> Option<(i64, i64)> {
+    match precision {
+        Precision::BillionYears => Some((1_000_000_000, 1_000_000_000)),
+        Precision::HundredMillionYears => Some((100_000_000, 1_000_000)),
+        Precision::TenMillionYears => Some((10_000_000, 1_000_000)),
+        Precision::MillionYears => Some((1_000_000, 1_000_000)),
+        Precision::HundredThousandYears => Some((100_000, 1)),
+        Precision::TenThousandYears => Some((10_000, 1)),
+        _ => None,
+    }
+}
+
+/// Renders `count` with `separator` as a digit-group separator, e.g. `10_000`
+/// with `,` renders `"10,000"`.
+fn group_digits(count: i64, separator: char) -> String {
+    let digits = count.to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped.chars().rev().collect()
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Era {
     year: i32,
     month: u8,
     day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
     precision: Precision,
     language: Language,
+    era_style: EraStyle,
+    numbering: Numbering,
+    calendar: Calendar,
 }
 
 impl Era {
@@ -50,11 +176,86 @@ impl Era {
         Self::new(year, 0, 0, Precision::Millennium)
     }
 
+    /// Pass a year to set the date to the nearest 10,000 years
+    pub fn ten_thousand_years(year: i32) -> Self {
+        Self::new(year, 0, 0, Precision::TenThousandYears)
+    }
+
+    /// Pass a year to set the date to the nearest 100,000 years
+    pub fn hundred_thousand_years(year: i32) -> Self {
+        Self::new(year, 0, 0, Precision::HundredThousandYears)
+    }
+
+    /// Pass a year to set the date to the nearest million years
+    pub fn million_years(year: i32) -> Self {
+        Self::new(year, 0, 0, Precision::MillionYears)
+    }
+
+    /// Pass a year to set the date to the nearest ten million years
+    pub fn ten_million_years(year: i32) -> Self {
+        Self::new(year, 0, 0, Precision::TenMillionYears)
+    }
+
+    /// Pass a year to set the date to the nearest hundred million years
+    pub fn hundred_million_years(year: i32) -> Self {
+        Self::new(year, 0, 0, Precision::HundredMillionYears)
+    }
+
+    /// Pass a year to set the date to the nearest billion years
+    pub fn billion_years(year: i32) -> Self {
+        Self::new(year, 0, 0, Precision::BillionYears)
+    }
+
+    /// Pass a year, month, day, and hour
+    pub fn hour(year: i32, month: u8, day: u8, hour: u8) -> Self {
+        let mut era = Self::new(year, month, day, Precision::Hour);
+        era.hour = hour;
+        era
+    }
+
+    /// Pass a year, month, day, hour, and minute
+    pub fn minute(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> Self {
+        let mut era = Self::new(year, month, day, Precision::Minute);
+        era.hour = hour;
+        era.minute = minute;
+        era
+    }
+
+    /// Pass a year, month, day, hour, minute, and second
+    pub fn second(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        let mut era = Self::new(year, month, day, Precision::Second);
+        era.hour = hour;
+        era.minute = minute;
+        era.second = second;
+        era
+    }
+
     pub fn language(mut self, language: Language) -> Self {
         self.language = language;
         self
     }
 
+    /// Sets which family of era labels (CE/BCE, AD/BC, ...) is used when
+    /// rendering this date.
+    pub fn era_style(mut self, era_style: EraStyle) -> Self {
+        self.era_style = era_style;
+        self
+    }
+
+    /// Sets whether the stored year follows astronomical or historical
+    /// numbering. See [`Numbering`].
+    pub fn numbering(mut self, numbering: Numbering) -> Self {
+        self.numbering = numbering;
+        self
+    }
+
+    /// Sets which calendar system the stored year/month/day are expressed
+    /// in. See [`Calendar`].
+    pub fn calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
     /// Pass year, month, day, and precision.
     /// Depending on precision, day and/or month will be ignored.
     pub fn new(year: i32, month: u8, day: u8, precision: Precision) -> Self {
@@ -62,56 +263,353 @@ impl Era {
             year,
             month,
             day,
+            hour: 0,
+            minute: 0,
+            second: 0,
             precision,
             language: Language::English, // Default language
+            era_style: EraStyle::default(),
+            numbering: Numbering::default(),
+            calendar: Calendar::default(),
+        }
+    }
+
+    /// Converts this date to the same instant under a different calendar
+    /// system, via an intermediate continuous day count.
+    ///
+    /// Months/days below [`Precision::Month`]/[`Precision::Day`] are treated
+    /// as the 1st of the year/month for the purpose of the conversion, and
+    /// reset back to their placeholder value afterwards. The result always
+    /// uses [`Numbering::Astronomical`], since the conversion math has no
+    /// notion of historical numbering.
+    pub fn to_calendar(&self, target: Calendar) -> Self {
+        let month = if self.month == 0 { 1 } else { self.month };
+        let day = if self.day == 0 { 1 } else { self.day };
+        let days = calendar::to_day_count(&self.calendar, self.astronomical_year(), month, day);
+        let (year, month, day) = calendar::from_day_count(&target, days);
+        let (month, day) = match self.precision {
+            Precision::Day | Precision::Hour | Precision::Minute | Precision::Second => {
+                (month, day)
+            }
+            Precision::Month => (month, 0),
+            _ => (0, 0),
+        };
+
+        Self {
+            year,
+            month,
+            day,
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            precision: self.precision.clone(),
+            language: self.language.clone(),
+            era_style: self.era_style.clone(),
+            numbering: Numbering::Astronomical,
+            calendar: target,
+        }
+    }
+
+    /// The stored year, converted to astronomical numbering (year 0 exists).
+    fn astronomical_year(&self) -> i32 {
+        match self.numbering {
+            Numbering::Astronomical => self.year,
+            Numbering::Historical => {
+                if self.year < 0 {
+                    self.year + 1
+                } else {
+                    self.year
+                }
+            }
+        }
+    }
+
+    /// Returns `(is_ce, absolute_year)` under historical-style numbering,
+    /// e.g. astronomical year 0 is `(false, 1)` ("1 BCE") and astronomical
+    /// year -1 is `(false, 2)` ("2 BCE").
+    pub fn year_ce(&self) -> (bool, u32) {
+        let year = self.astronomical_year();
+        if year > 0 {
+            (true, year as u32)
+        } else {
+            // Widen to i64 first: `1 - year` overflows i32 when `year` is
+            // `i32::MIN`.
+            (false, (1_i64 - i64::from(year)) as u32)
         }
     }
 
-    fn era(&self) -> &str {
-        self.language.era(self.year)
+    fn era(&self, year: i32) -> &str {
+        self.language.era_suffix(year, &self.era_style)
     }
 
     fn year_to_decade(&self) -> String {
-        if self.year == 0 {
+        let astronomical_year = self.astronomical_year();
+        if astronomical_year == 0 {
             return "0".to_string();
         }
-        let year = (self.year.abs() / 10) * 10;
-        let era = self.era();
+        let year = (astronomical_year.abs() / 10) * 10;
+        let era = self.era(astronomical_year);
         let factor = self.language.decade();
         format!("{year}{factor}{era}")
     }
 
     fn year_to_century(&self) -> String {
-        if self.year == 0 {
+        let astronomical_year = self.astronomical_year();
+        if astronomical_year == 0 {
             return "0".to_string();
         }
-        let year = (self.year.abs() + 99) / 100;
+        let year = (astronomical_year.abs() + 99) / 100;
         let ext = self.language.extension(year);
-        let era = self.era();
+        let era = self.era(astronomical_year);
         let factor = self.language.century();
         format!("{year}{ext} {factor}{era}")
     }
 
     fn year_to_millennium(&self) -> String {
-        if self.year == 0 {
+        let astronomical_year = self.astronomical_year();
+        if astronomical_year == 0 {
             return "0".to_string();
         }
-        let year = (self.year.abs() + 999) / 1000;
+        let year = (astronomical_year.abs() + 999) / 1000;
         let ext = self.language.extension(year);
-        let era = self.era();
+        let era = self.era(astronomical_year);
         let factor = self.language.millennium();
         format!("{year}{ext} {factor}{era}")
     }
 
+    /// Renders a coarse bucket (10,000 years or coarser) as a rounded span,
+    /// e.g. "10,000 years" or "1 million years BCE".
+    fn year_to_coarse(&self) -> String {
+        let astronomical_year = self.astronomical_year();
+        if astronomical_year == 0 {
+            return "0".to_string();
+        }
+        let (bucket_unit, word_unit) = coarse_bucket(&self.precision)
+            .expect("year_to_coarse is only called for a coarse precision");
+        let magnitude = astronomical_year.unsigned_abs() as i64;
+        let rounded = (magnitude + bucket_unit / 2) / bucket_unit * bucket_unit;
+        let count = rounded / word_unit;
+        let era = self.era(astronomical_year);
+        let years = self.language.years_word();
+        if word_unit == 1 {
+            let count = group_digits(count, self.language.thousands_separator());
+            format!("{count} {years}{era}")
+        } else {
+            let word = self.language.magnitude_word(word_unit, count);
+            format!("{count} {word} {years}{era}")
+        }
+    }
+
     fn as_string(&self) -> String {
+        let year = self.astronomical_year();
         match self.precision {
+            Precision::BillionYears
+            | Precision::HundredMillionYears
+            | Precision::TenMillionYears
+            | Precision::MillionYears
+            | Precision::HundredThousandYears
+            | Precision::TenThousandYears => self.year_to_coarse(),
             Precision::Millennium => self.year_to_millennium(),
             Precision::Century => self.year_to_century(),
             Precision::Decade => self.year_to_decade(),
-            Precision::Year => format!("{}", self.year),
-            Precision::Month => format!("{}-{:0>2}", self.year, self.month),
-            Precision::Day => format!("{}-{:0>2}-{:0>2}", self.year, self.month, self.day),
+            Precision::Year => format!("{year}"),
+            Precision::Month => format!("{year}-{:0>2}", self.month),
+            Precision::Day => format!("{year}-{:0>2}-{:0>2}", self.month, self.day),
+            Precision::Hour => format!(
+                "{year}-{:0>2}-{:0>2}T{:0>2}",
+                self.month, self.day, self.hour
+            ),
+            Precision::Minute => format!(
+                "{year}-{:0>2}-{:0>2}T{:0>2}:{:0>2}",
+                self.month, self.day, self.hour, self.minute
+            ),
+            Precision::Second => format!(
+                "{year}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}",
+                self.month, self.day, self.hour, self.minute, self.second
+            ),
+        }
+    }
+
+    /// Renders this date using a strftime-like pattern.
+    ///
+    /// Recognized tokens: `%Y` (signed year), `%y` (zero-padded absolute
+    /// year), `%m`/`%d` (zero-padded month/day), `%C`/`%MIL`/`%DEC`
+    /// (century/millennium/decade word forms in the current language), `%E`
+    /// (era suffix, without a leading space), and literal passthrough for
+    /// everything else. For example `"%y %E"` renders "2024 CE" under
+    /// [`EraStyle::CeBce`] (the default style gives "2024 "), and
+    /// `"%C (%Y)"` renders "10th century (910)".
+    pub fn format(&self, pattern: &str) -> Result<FormattedEra, FormatEraError> {
+        let mut output = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(c);
+                continue;
+            }
+            let rest: String = chars.clone().collect();
+            if rest.starts_with("MIL") {
+                chars.nth(2);
+                output.push_str(&self.year_to_millennium());
+            } else if rest.starts_with("DEC") {
+                chars.nth(2);
+                output.push_str(&self.year_to_decade());
+            } else {
+                match chars.next() {
+                    Some('Y') => output.push_str(&self.astronomical_year().to_string()),
+                    Some('y') => {
+                        output.push_str(&format!("{:04}", self.astronomical_year().unsigned_abs()))
+                    }
+                    Some('m') => output.push_str(&format!("{:02}", self.month)),
+                    Some('d') => output.push_str(&format!("{:02}", self.day)),
+                    Some('C') => output.push_str(&self.year_to_century()),
+                    Some('E') => output.push_str(self.era(self.astronomical_year()).trim_start()),
+                    Some(other) => return Err(FormatEraError::UnknownToken(other)),
+                    None => return Err(FormatEraError::TrailingPercent),
+                }
+            }
         }
+        Ok(FormattedEra(output))
+    }
+
+    /// Reconstructs an `Era` from text previously produced by its `Display`
+    /// implementation, inferring the [`Precision`] from the shape of `s`.
+    ///
+    /// The result always uses [`Numbering::Astronomical`], since rendered
+    /// text carries no indication of which numbering convention was used to
+    /// produce it.
+    ///
+    /// Only the `Millennium` through `Day` shapes round-trip. The coarse bucket
+    /// precisions (`TenThousandYears` and coarser, rendered as e.g. "2
+    /// million years BCE") and the time-of-day precisions (`Hour`/`Minute`/
+    /// `Second`, rendered with a `T...` suffix) have no parser yet and are
+    /// rejected with [`ParseEraError::UnrecognizedFormat`].
+    pub fn parse(s: &str, language: Language) -> Result<Self, ParseEraError> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(ParseEraError::Empty);
+        }
+
+        let (core, is_negative, era_style) = Self::strip_era_suffix(trimmed, &language);
+
+        if let Some(abs_year) = core
+            .strip_suffix(language.decade())
+            .and_then(|rest| rest.parse::<i32>().ok())
+        {
+            let year = if is_negative { -abs_year } else { abs_year };
+            return Ok(Self::new(year, 0, 0, Precision::Decade)
+                .language(language)
+                .era_style(era_style));
+        }
+
+        if let Some(n) = Self::parse_ordinal_word(core, &language, language.century()) {
+            let abs_year = Self::ordinal_to_year(n, 100)
+                .ok_or_else(|| ParseEraError::UnrecognizedFormat(trimmed.to_string()))?;
+            let year = if is_negative { -abs_year } else { abs_year };
+            return Ok(Self::new(year, 0, 0, Precision::Century)
+                .language(language)
+                .era_style(era_style));
+        }
+
+        if let Some(n) = Self::parse_ordinal_word(core, &language, language.millennium()) {
+            let abs_year = Self::ordinal_to_year(n, 1000)
+                .ok_or_else(|| ParseEraError::UnrecognizedFormat(trimmed.to_string()))?;
+            let year = if is_negative { -abs_year } else { abs_year };
+            return Ok(Self::new(year, 0, 0, Precision::Millennium)
+                .language(language)
+                .era_style(era_style));
+        }
+
+        if let Some((year, month, day, precision)) = Self::parse_numeric_date(core) {
+            return Ok(Self::new(year, month, day, precision)
+                .language(language)
+                .era_style(era_style));
+        }
+
+        Err(ParseEraError::UnrecognizedFormat(trimmed.to_string()))
+    }
+
+    /// Strips a trailing era suffix (if any), returning the remaining text,
+    /// whether the suffix denoted a negative (BCE-like) year, and the
+    /// [`EraStyle`] it belongs to.
+    fn strip_era_suffix<'s>(s: &'s str, language: &Language) -> (&'s str, bool, EraStyle) {
+        for (suffix, is_negative, style) in era_suffixes(language) {
+            if let Some(stripped) = s.strip_suffix(suffix) {
+                return (stripped.trim_end(), *is_negative, style.clone());
+            }
+        }
+        (s, false, EraStyle::Implicit)
+    }
+
+    /// Matches `"<ordinal> <word>"` (e.g. "10th century", "10. Jahrhundert"),
+    /// returning the parsed ordinal number on success.
+    fn parse_ordinal_word(core: &str, language: &Language, word: &str) -> Option<u32> {
+        let (number, rest) = core.split_once(' ')?;
+        if rest != word {
+            return None;
+        }
+        Self::parse_ordinal_number(number, language)
+    }
+
+    fn parse_ordinal_number(token: &str, language: &Language) -> Option<u32> {
+        match language {
+            Language::English => ["st", "nd", "rd", "th"]
+                .iter()
+                .find_map(|suffix| token.strip_suffix(suffix)?.parse().ok()),
+            Language::German => token.strip_suffix('.')?.parse().ok(),
+        }
+    }
+
+    /// Converts a 1-based ordinal (e.g. "10" in "10th century") and the
+    /// ordinal's unit length in years into the absolute year of its first
+    /// year, returning `None` if the ordinal's last year (`n * unit`) doesn't
+    /// fit in an `i32` — this keeps a crafted ordinal like "21474837th
+    /// century" from producing a year that later overflows when rendered.
+    fn ordinal_to_year(n: u32, unit: i32) -> Option<i32> {
+        // Rendering adds `unit - 1` to the first year, which reaches `n * unit`.
+        let last_year = i64::from(n).checked_mul(i64::from(unit))?;
+        i32::try_from(last_year).ok()?;
+        let year = (i64::from(n) - 1)
+            .checked_mul(i64::from(unit))?
+            .checked_add(1)?;
+        i32::try_from(year).ok()
+    }
+
+    /// Matches the ISO-like `"Y"`, `"Y-M"`, and `"Y-M-D"` shapes, returning
+    /// `(year, month, day, precision)`.
+    fn parse_numeric_date(core: &str) -> Option<(i32, u8, u8, Precision)> {
+        let (negative, unsigned) = match core.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, core),
+        };
+        let sign = if negative { -1 } else { 1 };
+        let parts: Vec<&str> = unsigned.split('-').collect();
+        match parts.as_slice() {
+            [year] => {
+                let year = year.parse::<i32>().ok()?;
+                Some((sign * year, 0, 0, Precision::Year))
+            }
+            [year, month] => {
+                let year = year.parse::<i32>().ok()?;
+                let month = month.parse::<u8>().ok()?;
+                Some((sign * year, month, 0, Precision::Month))
+            }
+            [year, month, day] => {
+                let year = year.parse::<i32>().ok()?;
+                let month = month.parse::<u8>().ok()?;
+                let day = day.parse::<u8>().ok()?;
+                Some((sign * year, month, day, Precision::Day))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Era {
+    type Err = ParseEraError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Language::English)
     }
 }
 
@@ -192,6 +690,58 @@ mod tests {
         assert_eq!(Era::millennium(0).to_string(), "0");
     }
 
+    #[test]
+    fn test_date_renderer_coarse_years() {
+        assert_eq!(Era::ten_thousand_years(12_345).to_string(), "10,000 years");
+        assert_eq!(
+            Era::ten_thousand_years(-12_345).to_string(),
+            "10,000 years BCE"
+        );
+        assert_eq!(
+            Era::hundred_thousand_years(1_234_567).to_string(),
+            "1,200,000 years"
+        );
+        assert_eq!(Era::million_years(1_600_000).to_string(), "2 million years");
+        assert_eq!(
+            Era::ten_million_years(24_000_000).to_string(),
+            "20 million years"
+        );
+        assert_eq!(
+            Era::hundred_million_years(251_000_000).to_string(),
+            "300 million years"
+        );
+        assert_eq!(
+            Era::billion_years(-1_500_000_000).to_string(),
+            "2 billion years BCE"
+        );
+        assert_eq!(Era::million_years(0).to_string(), "0");
+    }
+
+    #[test]
+    fn test_date_renderer_coarse_years_german() {
+        let dr = Era::million_years(1_000_000).language(Language::German);
+        assert_eq!(dr.to_string(), "1 Million Jahre");
+        let dr = Era::million_years(2_000_000).language(Language::German);
+        assert_eq!(dr.to_string(), "2 Millionen Jahre");
+        let dr = Era::billion_years(1_000_000_000).language(Language::German);
+        assert_eq!(dr.to_string(), "1 Milliarde Jahre");
+        let dr = Era::ten_thousand_years(10_000).language(Language::German);
+        assert_eq!(dr.to_string(), "10.000 Jahre");
+    }
+
+    #[test]
+    fn test_date_renderer_time_of_day() {
+        assert_eq!(Era::hour(2024, 10, 2, 14).to_string(), "2024-10-02T14");
+        assert_eq!(
+            Era::minute(2024, 10, 2, 14, 30).to_string(),
+            "2024-10-02T14:30"
+        );
+        assert_eq!(
+            Era::second(2024, 10, 2, 14, 30, 5).to_string(),
+            "2024-10-02T14:30:05"
+        );
+    }
+
     #[test]
     fn test_data_render_german() {
         let dr = Era::decade(910).language(Language::German);
@@ -225,4 +775,328 @@ mod tests {
         let dr = Era::date(&date, Precision::Day);
         assert_eq!(dr.to_string(), "-910-09-17");
     }
+
+    #[test]
+    fn test_era_style() {
+        assert_eq!(Era::year(2024).to_string(), "2024");
+        assert_eq!(
+            Era::year(2024).era_style(EraStyle::CeBce).to_string(),
+            "2024"
+        );
+        assert_eq!(
+            Era::century(910).era_style(EraStyle::CeBce).to_string(),
+            "10th century CE"
+        );
+        assert_eq!(
+            Era::century(-910).era_style(EraStyle::CeBce).to_string(),
+            "10th century BCE"
+        );
+        assert_eq!(
+            Era::century(910).era_style(EraStyle::AdBc).to_string(),
+            "10th century AD"
+        );
+        assert_eq!(
+            Era::century(-910).era_style(EraStyle::AdBc).to_string(),
+            "10th century BC"
+        );
+        assert_eq!(
+            Era::century(910)
+                .language(Language::German)
+                .era_style(EraStyle::CeBce)
+                .to_string(),
+            "10. Jahrhundert u.Z."
+        );
+        assert_eq!(
+            Era::century(910)
+                .language(Language::German)
+                .era_style(EraStyle::AdBc)
+                .to_string(),
+            "10. Jahrhundert n.Chr."
+        );
+    }
+
+    #[test]
+    fn test_year_ce() {
+        assert_eq!(Era::year(2024).year_ce(), (true, 2024));
+        assert_eq!(Era::year(1).year_ce(), (true, 1));
+        assert_eq!(Era::year(0).year_ce(), (false, 1));
+        assert_eq!(Era::year(-1).year_ce(), (false, 2));
+        assert_eq!(Era::year(-910).year_ce(), (false, 911));
+        assert_eq!(
+            Era::year(i32::MIN).year_ce(),
+            (false, i32::MIN.unsigned_abs() + 1)
+        );
+    }
+
+    #[test]
+    fn test_historical_numbering() {
+        // Historical year -1 ("1 BCE") is astronomical year 0.
+        let historical = Era::year(-1).numbering(Numbering::Historical);
+        assert_eq!(historical.year_ce(), (false, 1));
+        assert_eq!(historical.to_string(), "0");
+
+        // Positive years are identical in both conventions.
+        let historical = Era::year(910).numbering(Numbering::Historical);
+        assert_eq!(historical.year_ce(), (true, 910));
+
+        let historical = Era::century(-901).numbering(Numbering::Historical);
+        assert_eq!(historical.to_string(), "9th century BCE");
+    }
+
+    #[test]
+    fn test_parse_day_month_year() {
+        assert_eq!(
+            Era::parse("2024-10-02", Language::English).unwrap(),
+            Era::day(2024, 10, 2)
+        );
+        assert_eq!(
+            Era::parse("-2024-10-02", Language::English).unwrap(),
+            Era::day(-2024, 10, 2)
+        );
+        assert_eq!(
+            Era::parse("2024-10", Language::English).unwrap(),
+            Era::month(2024, 10)
+        );
+        assert_eq!(
+            Era::parse("2024", Language::English).unwrap(),
+            Era::year(2024)
+        );
+        assert_eq!(
+            Era::parse("-2024", Language::English).unwrap(),
+            Era::year(-2024)
+        );
+    }
+
+    #[test]
+    fn test_parse_decade() {
+        assert_eq!(
+            Era::parse("910s", Language::English).unwrap(),
+            Era::decade(910)
+        );
+        assert_eq!(
+            Era::parse("910s BCE", Language::English).unwrap(),
+            Era::decade(-910)
+        );
+        assert_eq!(
+            Era::parse("910er", Language::German).unwrap(),
+            Era::decade(910).language(Language::German)
+        );
+        assert_eq!(
+            Era::parse("910er v.Chr.", Language::German).unwrap(),
+            Era::decade(-910).language(Language::German)
+        );
+    }
+
+    #[test]
+    fn test_parse_century_and_millennium() {
+        assert_eq!(
+            Era::parse("10th century", Language::English)
+                .unwrap()
+                .to_string(),
+            "10th century"
+        );
+        assert_eq!(
+            Era::parse("10th century BCE", Language::English)
+                .unwrap()
+                .to_string(),
+            "10th century BCE"
+        );
+        assert_eq!(
+            Era::parse("10. Jahrhundert", Language::German)
+                .unwrap()
+                .to_string(),
+            "10. Jahrhundert"
+        );
+        assert_eq!(
+            Era::parse("10. Jahrhundert v.Chr.", Language::German)
+                .unwrap()
+                .to_string(),
+            "10. Jahrhundert v.Chr."
+        );
+        assert_eq!(
+            Era::parse("3rd millennium", Language::English)
+                .unwrap()
+                .to_string(),
+            "3rd millennium"
+        );
+        assert_eq!(
+            Era::parse("2nd millennium BCE", Language::English)
+                .unwrap()
+                .to_string(),
+            "2nd millennium BCE"
+        );
+    }
+
+    #[test]
+    fn test_parse_recovers_era_style() {
+        let parsed = Era::parse("10th century CE", Language::English).unwrap();
+        assert_eq!(parsed.to_string(), "10th century CE");
+        let parsed = Era::parse("10th century AD", Language::English).unwrap();
+        assert_eq!(parsed.to_string(), "10th century AD");
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let eras = [
+            Era::day(2024, 10, 2),
+            Era::month(-2024, 10),
+            Era::year(2024),
+            Era::decade(-910),
+            Era::century(910).era_style(EraStyle::CeBce),
+            Era::millennium(-3001),
+        ];
+        for era in eras {
+            let rendered = era.to_string();
+            let parsed = Era::parse(&rendered, Language::English).unwrap();
+            assert_eq!(parsed.to_string(), rendered);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Era::parse("", Language::English), Err(ParseEraError::Empty));
+        assert!(Era::parse("not a date", Language::English).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_ordinal_that_would_overflow() {
+        // An ordinal this large would multiply out into a year that doesn't
+        // fit in an i32, and previously panicked when the result was later
+        // rendered. It must be rejected at parse time instead.
+        assert_eq!(
+            Era::parse("21474837th century", Language::English),
+            Err(ParseEraError::UnrecognizedFormat(
+                "21474837th century".to_string()
+            ))
+        );
+        assert!(Era::parse("21474837th millennium", Language::English).is_err());
+    }
+
+    #[test]
+    fn test_parse_does_not_cover_coarse_or_time_of_day() {
+        // Documented gap: coarse bucket and time-of-day shapes don't
+        // round-trip through `Era::parse` yet.
+        let coarse = Era::million_years(1_600_000).to_string();
+        assert_eq!(
+            Era::parse(&coarse, Language::English),
+            Err(ParseEraError::UnrecognizedFormat(coarse))
+        );
+
+        let time_of_day = Era::hour(2024, 10, 2, 14).to_string();
+        assert_eq!(
+            Era::parse(&time_of_day, Language::English),
+            Err(ParseEraError::UnrecognizedFormat(time_of_day))
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("2024-10-02".parse::<Era>().unwrap(), Era::day(2024, 10, 2));
+        assert!("garbage".parse::<Era>().is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        let era = Era::year(2024).era_style(EraStyle::CeBce);
+        assert_eq!(era.format("%y %E").unwrap().to_string(), "2024 CE");
+
+        let era = Era::day(2024, 10, 2);
+        assert_eq!(era.format("%Y-%m-%d").unwrap().to_string(), "2024-10-02");
+
+        let era = Era::century(910);
+        assert_eq!(
+            era.format("%C (%Y)").unwrap().to_string(),
+            "10th century (910)"
+        );
+
+        let era = Era::millennium(-3001);
+        assert_eq!(
+            era.format("%MIL").unwrap().to_string(),
+            "4th millennium BCE"
+        );
+
+        let era = Era::decade(910).language(Language::German);
+        assert_eq!(era.format("%DEC").unwrap().to_string(), "910er");
+
+        assert_eq!(
+            Era::year(2024).format("literal text").unwrap().to_string(),
+            "literal text"
+        );
+    }
+
+    #[test]
+    fn test_to_calendar() {
+        // The day after 4 October 1582 (Julian) was 15 October 1582
+        // (Gregorian), when the reform took effect.
+        let julian = Era::day(1582, 10, 4).calendar(Calendar::Julian);
+        let gregorian = julian.to_calendar(Calendar::Gregorian);
+        assert_eq!(gregorian.year_ce(), (true, 1582));
+        assert_eq!(gregorian.to_string(), "1582-10-14");
+
+        // Round-tripping back to Julian recovers the original date.
+        assert_eq!(
+            gregorian.to_calendar(Calendar::Julian).to_string(),
+            "1582-10-04"
+        );
+    }
+
+    #[test]
+    fn test_to_calendar_time_of_day_precision() {
+        // Time-of-day precisions must keep the converted month/day, not reset
+        // them like the coarser-than-Month precisions do.
+        let julian = Era::hour(1582, 10, 4, 14).calendar(Calendar::Julian);
+        assert_eq!(
+            julian.to_calendar(Calendar::Gregorian).to_string(),
+            "1582-10-14T14"
+        );
+
+        let julian = Era::minute(1582, 10, 4, 14, 30).calendar(Calendar::Julian);
+        assert_eq!(
+            julian.to_calendar(Calendar::Gregorian).to_string(),
+            "1582-10-14T14:30"
+        );
+
+        let julian = Era::second(1582, 10, 4, 14, 30, 5).calendar(Calendar::Julian);
+        assert_eq!(
+            julian.to_calendar(Calendar::Gregorian).to_string(),
+            "1582-10-14T14:30:05"
+        );
+    }
+
+    #[test]
+    fn test_to_calendar_preserves_precision() {
+        let era = Era::year(2024).calendar(Calendar::ProlepticJulian);
+        let converted = era.to_calendar(Calendar::Gregorian);
+        assert_eq!(converted.to_string(), era.to_string());
+        assert_eq!(converted.month, 0);
+        assert_eq!(converted.day, 0);
+    }
+
+    #[test]
+    fn test_to_calendar_year_precision_can_shift_across_new_year() {
+        // `to_calendar` treats the undisplayed month/day of `Era::year` as
+        // 1 January. Deep in
+        // the past the Julian and Gregorian calendars have drifted apart by
+        // more than a day, so that nominal 1 January can fall on the other
+        // side of the Gregorian new year boundary — shifting the displayed
+        // year by one. This is correct, not a bug: it only ever happens where
+        // the two calendars' new years don't coincide.
+        let era = Era::year(-910).calendar(Calendar::ProlepticJulian);
+        let converted = era.to_calendar(Calendar::Gregorian);
+        assert_eq!(converted.to_string(), "-911");
+        assert_eq!(converted.month, 0);
+        assert_eq!(converted.day, 0);
+    }
+
+    #[test]
+    fn test_format_errors() {
+        assert_eq!(
+            Era::year(2024).format("%Q"),
+            Err(FormatEraError::UnknownToken('Q'))
+        );
+        assert_eq!(
+            Era::year(2024).format("trailing %"),
+            Err(FormatEraError::TrailingPercent)
+        );
+    }
 }
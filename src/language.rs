@@ -6,27 +6,78 @@ pub enum Language {
     English,
 }
 
+/// Chooses which family of era labels is used when rendering a year.
+///
+/// `Implicit` mirrors the historical default of this crate: no suffix for
+/// years on or after the epoch, and a bare BCE-style suffix for years before
+/// it. The other variants attach an explicit suffix on both sides of the
+/// epoch. Suffixes are rendered at decade precision and coarser (and by the
+/// `%E` format token); `Year`, `Month`, `Day` and time-of-day precisions
+/// render the signed year with no suffix under any style.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum EraStyle {
+    #[default]
+    Implicit,
+    /// Secular style: CE/BCE in English, u.Z./v.u.Z. in German.
+    CeBce,
+    /// Traditional style: AD/BC in English, n.Chr./v.Chr. in German.
+    AdBc,
+}
+
+/// A CLDR-style ordinal plural rule: `predicate` tests the (non-negative)
+/// cardinal value `n`, and `suffix` is emitted for the first rule that
+/// matches. Rules are evaluated in order; the last rule of each table is the
+/// language's unconditional `other` category, and `extension` panics if no
+/// rule matches.
+type OrdinalRule = (fn(i32) -> bool, &'static str);
+
+const ENGLISH_ORDINALS: &[OrdinalRule] = &[
+    (|n| n % 10 == 1 && n % 100 != 11, "st"), // one
+    (|n| n % 10 == 2 && n % 100 != 12, "nd"), // two
+    (|n| n % 10 == 3 && n % 100 != 13, "rd"), // few
+    (|_| true, "th"),                         // other
+];
+
+// German does not have ordinal suffixes beyond a trailing period.
+const GERMAN_ORDINALS: &[OrdinalRule] = &[(|_| true, ".")];
+
 impl Language {
-    pub(crate) fn extension(&self, year: i32) -> &str {
+    fn ordinal_rules(&self) -> &'static [OrdinalRule] {
         match self {
-            Language::German => ".", // German does not have extensions
-            Language::English => match year.abs() % 10 {
-                1 => "st",
-                2 => "nd",
-                3 => "rd",
-                _ => "th",
-            },
+            Language::German => GERMAN_ORDINALS,
+            Language::English => ENGLISH_ORDINALS,
         }
     }
 
-    pub(crate) fn era(&self, year: i32) -> &str {
-        if year < 0 {
-            match self {
-                Language::German => " v.Chr.",
-                Language::English => " BCE",
-            }
-        } else {
-            ""
+    /// Returns the ordinal suffix for the cardinal number `year` (its
+    /// magnitude is all that matters), using this language's CLDR-style
+    /// plural-category rules.
+    pub(crate) fn extension(&self, year: i32) -> &str {
+        let n = year.abs();
+        self.ordinal_rules()
+            .iter()
+            .find(|(predicate, _)| predicate(n))
+            .map(|(_, suffix)| *suffix)
+            .expect("the last rule in every table is an unconditional fallback")
+    }
+
+    /// Returns the era suffix (including a leading space where applicable)
+    /// for `year`, under the given `style`.
+    pub(crate) fn era_suffix(&self, year: i32, style: &EraStyle) -> &str {
+        let negative = year < 0;
+        match (self, style, negative) {
+            (Language::English, EraStyle::Implicit, true) => " BCE",
+            (Language::English, EraStyle::Implicit, false) => "",
+            (Language::English, EraStyle::CeBce, true) => " BCE",
+            (Language::English, EraStyle::CeBce, false) => " CE",
+            (Language::English, EraStyle::AdBc, true) => " BC",
+            (Language::English, EraStyle::AdBc, false) => " AD",
+            (Language::German, EraStyle::Implicit, true) => " v.Chr.",
+            (Language::German, EraStyle::Implicit, false) => "",
+            (Language::German, EraStyle::CeBce, true) => " v.u.Z.",
+            (Language::German, EraStyle::CeBce, false) => " u.Z.",
+            (Language::German, EraStyle::AdBc, true) => " v.Chr.",
+            (Language::German, EraStyle::AdBc, false) => " n.Chr.",
         }
     }
 
@@ -50,6 +101,39 @@ impl Language {
             Language::English => "millennium",
         }
     }
+
+    /// The plain word for "years", written after the count (and after the
+    /// million/billion word, if any) when rendering a coarse
+    /// [`Precision`](crate::Precision) bucket.
+    pub(crate) fn years_word(&self) -> &str {
+        match self {
+            Language::German => "Jahre",
+            Language::English => "years",
+        }
+    }
+
+    /// The word for a `unit`-sized multiplier (a million or a billion),
+    /// pluralized for `count` where the language distinguishes singular and
+    /// plural forms (German).
+    pub(crate) fn magnitude_word(&self, unit: i64, count: i64) -> &str {
+        match (self, unit) {
+            (Language::English, 1_000_000) => "million",
+            (Language::English, 1_000_000_000) => "billion",
+            (Language::German, 1_000_000) if count == 1 => "Million",
+            (Language::German, 1_000_000) => "Millionen",
+            (Language::German, 1_000_000_000) if count == 1 => "Milliarde",
+            (Language::German, 1_000_000_000) => "Milliarden",
+            _ => "",
+        }
+    }
+
+    /// The digit-grouping separator used when rendering large year counts.
+    pub(crate) fn thousands_separator(&self) -> char {
+        match self {
+            Language::German => '.',
+            Language::English => ',',
+        }
+    }
 }
 
 /// Sets the language from a string, defaults to English if the language is not supported.
@@ -81,4 +165,90 @@ mod tests {
         assert_eq!(Language::from("en"), Language::English);
         assert_eq!(Language::from("foobar"), Language::English);
     }
+
+    #[test]
+    fn test_era_suffix() {
+        assert_eq!(Language::English.era_suffix(2024, &EraStyle::Implicit), "");
+        assert_eq!(
+            Language::English.era_suffix(-2024, &EraStyle::Implicit),
+            " BCE"
+        );
+        assert_eq!(Language::English.era_suffix(2024, &EraStyle::CeBce), " CE");
+        assert_eq!(
+            Language::English.era_suffix(-2024, &EraStyle::CeBce),
+            " BCE"
+        );
+        assert_eq!(Language::English.era_suffix(2024, &EraStyle::AdBc), " AD");
+        assert_eq!(Language::English.era_suffix(-2024, &EraStyle::AdBc), " BC");
+
+        assert_eq!(Language::German.era_suffix(2024, &EraStyle::Implicit), "");
+        assert_eq!(
+            Language::German.era_suffix(-2024, &EraStyle::Implicit),
+            " v.Chr."
+        );
+        assert_eq!(Language::German.era_suffix(2024, &EraStyle::CeBce), " u.Z.");
+        assert_eq!(
+            Language::German.era_suffix(-2024, &EraStyle::CeBce),
+            " v.u.Z."
+        );
+        assert_eq!(
+            Language::German.era_suffix(2024, &EraStyle::AdBc),
+            " n.Chr."
+        );
+        assert_eq!(
+            Language::German.era_suffix(-2024, &EraStyle::AdBc),
+            " v.Chr."
+        );
+    }
+
+    #[test]
+    fn test_extension_english() {
+        assert_eq!(Language::English.extension(1), "st");
+        assert_eq!(Language::English.extension(2), "nd");
+        assert_eq!(Language::English.extension(3), "rd");
+        assert_eq!(Language::English.extension(4), "th");
+        assert_eq!(Language::English.extension(11), "th");
+        assert_eq!(Language::English.extension(12), "th");
+        assert_eq!(Language::English.extension(13), "th");
+        assert_eq!(Language::English.extension(21), "st");
+        assert_eq!(Language::English.extension(22), "nd");
+        assert_eq!(Language::English.extension(23), "rd");
+        assert_eq!(Language::English.extension(111), "th");
+        assert_eq!(Language::English.extension(112), "th");
+        assert_eq!(Language::English.extension(113), "th");
+        assert_eq!(Language::English.extension(-13), "th");
+    }
+
+    #[test]
+    fn test_extension_german() {
+        assert_eq!(Language::German.extension(1), ".");
+        assert_eq!(Language::German.extension(11), ".");
+        assert_eq!(Language::German.extension(910), ".");
+    }
+
+    #[test]
+    fn test_magnitude_word() {
+        assert_eq!(Language::English.magnitude_word(1_000_000, 1), "million");
+        assert_eq!(Language::English.magnitude_word(1_000_000, 10), "million");
+        assert_eq!(
+            Language::English.magnitude_word(1_000_000_000, 1),
+            "billion"
+        );
+        assert_eq!(Language::German.magnitude_word(1_000_000, 1), "Million");
+        assert_eq!(Language::German.magnitude_word(1_000_000, 2), "Millionen");
+        assert_eq!(
+            Language::German.magnitude_word(1_000_000_000, 1),
+            "Milliarde"
+        );
+        assert_eq!(
+            Language::German.magnitude_word(1_000_000_000, 2),
+            "Milliarden"
+        );
+    }
+
+    #[test]
+    fn test_thousands_separator() {
+        assert_eq!(Language::English.thousands_separator(), ',');
+        assert_eq!(Language::German.thousands_separator(), '.');
+    }
 }
@@ -1,7 +1,9 @@
-pub mod date_renderer;
+pub mod calendar;
+pub mod era;
 pub mod language;
 pub mod precision;
 
-pub use date_renderer::DateRenderer;
-pub use language::Language;
+pub use calendar::Calendar;
+pub use era::{Era, FormatEraError, FormattedEra, Numbering, ParseEraError};
+pub use language::{EraStyle, Language};
 pub use precision::Precision;
@@ -1,24 +1,46 @@
 use std::fmt;
 
+/// A date's precision, using Wikidata's time-precision scale: values 0-5 are
+/// successively finer multi-year buckets (a billion years down to ten
+/// thousand years), 6-11 are millennium through day,
+/// and 12-14 extend below a day to hour/minute/second.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Precision {
-    Day = 11,
-    Month = 10,
-    Year = 9,
-    Decade = 8,
-    Century = 7,
+    BillionYears = 0,
+    HundredMillionYears = 1,
+    TenMillionYears = 2,
+    MillionYears = 3,
+    HundredThousandYears = 4,
+    TenThousandYears = 5,
     Millennium = 6,
+    Century = 7,
+    Decade = 8,
+    Year = 9,
+    Month = 10,
+    Day = 11,
+    Hour = 12,
+    Minute = 13,
+    Second = 14,
 }
 
 impl Precision {
     fn as_u8(&self) -> u8 {
         match self {
-            Precision::Day => 11,
-            Precision::Month => 10,
-            Precision::Year => 9,
-            Precision::Decade => 8,
-            Precision::Century => 7,
+            Precision::BillionYears => 0,
+            Precision::HundredMillionYears => 1,
+            Precision::TenMillionYears => 2,
+            Precision::MillionYears => 3,
+            Precision::HundredThousandYears => 4,
+            Precision::TenThousandYears => 5,
             Precision::Millennium => 6,
+            Precision::Century => 7,
+            Precision::Decade => 8,
+            Precision::Year => 9,
+            Precision::Month => 10,
+            Precision::Day => 11,
+            Precision::Hour => 12,
+            Precision::Minute => 13,
+            Precision::Second => 14,
         }
     }
 }
@@ -30,17 +52,28 @@ impl fmt::Display for Precision {
 }
 
 impl TryFrom<u8> for Precision {
-    type Error = &'static str;
+    type Error = String;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
+            0 => Ok(Precision::BillionYears),
+            1 => Ok(Precision::HundredMillionYears),
+            2 => Ok(Precision::TenMillionYears),
+            3 => Ok(Precision::MillionYears),
+            4 => Ok(Precision::HundredThousandYears),
+            5 => Ok(Precision::TenThousandYears),
             6 => Ok(Precision::Millennium),
             7 => Ok(Precision::Century),
             8 => Ok(Precision::Decade),
             9 => Ok(Precision::Year),
             10 => Ok(Precision::Month),
             11 => Ok(Precision::Day),
-            _ => Err("Unsupported precision value {value}; values 6-11 are supported"),
+            12 => Ok(Precision::Hour),
+            13 => Ok(Precision::Minute),
+            14 => Ok(Precision::Second),
+            _ => Err(format!(
+                "Unsupported precision value {value}; values 0-14 are supported"
+            )),
         }
     }
 }
@@ -51,33 +84,42 @@ mod tests {
 
     #[test]
     fn test_precision_as_u8() {
-        assert_eq!(Precision::Day.as_u8(), 11);
-        assert_eq!(Precision::Month.as_u8(), 10);
-        assert_eq!(Precision::Year.as_u8(), 9);
-        assert_eq!(Precision::Decade.as_u8(), 8);
-        assert_eq!(Precision::Century.as_u8(), 7);
+        assert_eq!(Precision::BillionYears.as_u8(), 0);
+        assert_eq!(Precision::HundredMillionYears.as_u8(), 1);
+        assert_eq!(Precision::TenMillionYears.as_u8(), 2);
+        assert_eq!(Precision::MillionYears.as_u8(), 3);
+        assert_eq!(Precision::HundredThousandYears.as_u8(), 4);
+        assert_eq!(Precision::TenThousandYears.as_u8(), 5);
         assert_eq!(Precision::Millennium.as_u8(), 6);
+        assert_eq!(Precision::Century.as_u8(), 7);
+        assert_eq!(Precision::Decade.as_u8(), 8);
+        assert_eq!(Precision::Year.as_u8(), 9);
+        assert_eq!(Precision::Month.as_u8(), 10);
+        assert_eq!(Precision::Day.as_u8(), 11);
+        assert_eq!(Precision::Hour.as_u8(), 12);
+        assert_eq!(Precision::Minute.as_u8(), 13);
+        assert_eq!(Precision::Second.as_u8(), 14);
     }
 
     #[test]
     fn test_precision_display() {
+        assert_eq!(format!("{}", Precision::BillionYears), "0");
+        assert_eq!(format!("{}", Precision::TenThousandYears), "5");
         assert_eq!(format!("{}", Precision::Day), "11");
-        assert_eq!(format!("{}", Precision::Month), "10");
-        assert_eq!(format!("{}", Precision::Year), "9");
-        assert_eq!(format!("{}", Precision::Decade), "8");
-        assert_eq!(format!("{}", Precision::Century), "7");
-        assert_eq!(format!("{}", Precision::Millennium), "6");
+        assert_eq!(format!("{}", Precision::Second), "14");
     }
 
     #[test]
     fn test_precision_from_u8() {
+        assert_eq!(Precision::try_from(0).unwrap(), Precision::BillionYears);
+        assert_eq!(Precision::try_from(5).unwrap(), Precision::TenThousandYears);
         assert_eq!(Precision::try_from(11).unwrap(), Precision::Day);
-        assert_eq!(Precision::try_from(10).unwrap(), Precision::Month);
-        assert_eq!(Precision::try_from(9).unwrap(), Precision::Year);
-        assert_eq!(Precision::try_from(8).unwrap(), Precision::Decade);
-        assert_eq!(Precision::try_from(7).unwrap(), Precision::Century);
-        assert_eq!(Precision::try_from(6).unwrap(), Precision::Millennium);
-        assert!(Precision::try_from(5).is_err());
-        assert!(Precision::try_from(12).is_err());
+        assert_eq!(Precision::try_from(12).unwrap(), Precision::Hour);
+        assert_eq!(Precision::try_from(13).unwrap(), Precision::Minute);
+        assert_eq!(Precision::try_from(14).unwrap(), Precision::Second);
+        assert_eq!(
+            Precision::try_from(15).unwrap_err(),
+            "Unsupported precision value 15; values 0-14 are supported"
+        );
     }
 }